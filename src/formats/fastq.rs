@@ -1,4 +1,5 @@
 use std::cmp::min;
+use std::io::{self, Write};
 
 use memchr::memchr;
 
@@ -17,6 +18,66 @@ pub struct FastqRecord<'a> {
     pub qual: &'a [u8],
 }
 
+impl<'a> FastqRecord<'a> {
+    /// Decode the raw quality bytes into Phred quality scores by subtracting
+    /// `offset` (33 for Sanger/Illumina 1.8+, 64 for older Illumina).
+    pub fn phred_scores(&self, offset: u8) -> impl Iterator<Item = u8> + '_ {
+        self.qual.iter().map(move |&b| b.saturating_sub(offset))
+    }
+
+    /// Map each Phred score to its per-base error probability, `10^(-q/10)`.
+    ///
+    /// Takes the `offset` explicitly (33 or 64), like [`FastqRecord::phred_scores`],
+    /// so the caller decides the encoding. A single record rarely spans enough of
+    /// the quality range for [`detect_quality_offset`] to be reliable; detect once
+    /// over the first chunk of a file and thread the result through here.
+    pub fn error_probs(&self, offset: u8) -> impl Iterator<Item = f64> + '_ {
+        self.phred_scores(offset)
+            .map(|q| 10f64.powf(-(f64::from(q)) / 10.0))
+    }
+
+    /// Validate that the sequence contains only DNA bases (ACGT, either case).
+    pub fn validate_dna(&self) -> bool {
+        validate_alphabet(self.seq, &DNA_TABLE)
+    }
+
+    /// Validate that the sequence contains only DNA bases or `N` (ACGTN,
+    /// either case).
+    pub fn validate_dnan(&self) -> bool {
+        validate_alphabet(self.seq, &DNAN_TABLE)
+    }
+}
+
+/// Guess the Phred offset used to encode a run of quality bytes.
+///
+/// Bytes below 59 can only occur under Phred+33 (Sanger/Illumina 1.8+); a
+/// minimum at or above 64 together with a maximum above 74 indicates the
+/// older Phred+64 encoding. Returns `None` when the range is ambiguous (e.g.
+/// an empty slice), so downstream code can fall back to a default.
+pub fn detect_quality_offset(qual: &[u8]) -> Option<u8> {
+    let mut min = u8::MAX;
+    let mut max = u8::MIN;
+    for &b in qual {
+        if b < min {
+            min = b;
+        }
+        if b > max {
+            max = b;
+        }
+    }
+    if max < min {
+        // empty input
+        return None;
+    }
+    if min < 59 {
+        Some(33)
+    } else if min >= 64 && max > 74 {
+        Some(64)
+    } else {
+        None
+    }
+}
+
 impl<'a> Sequence<'a> for FastqRecord<'a> {
     fn sequence(&self) -> &'a [u8] {
         self.seq
@@ -29,29 +90,73 @@ impl<'a> From<FastqRecord<'a>> for SequenceRecord<'a> {
     }
 }
 
+/// A FASTQ record whose sequence and quality were hard-wrapped across several
+/// lines and are therefore stitched into owned buffers rather than borrowed
+/// from the input (see [`MultilineFastqParser`]).
+///
+/// Kept distinct from the zero-copy [`FastqRecord`] so the common single-line
+/// path keeps handing back buffer-lifetime (`&'a`) slices through the
+/// [`Sequence`] trait; convert into an owned [`SequenceRecord`] with `.into()`
+/// to feed the record into generic code.
+#[derive(Debug)]
+pub struct MultilineFastqRecord<'a> {
+    pub id: &'a [u8],
+    pub seq: Vec<u8>,
+    pub id2: &'a [u8],
+    pub qual: Vec<u8>,
+}
+
+impl<'a> From<MultilineFastqRecord<'a>> for SequenceRecord<'a> {
+    fn from(fastq: MultilineFastqRecord<'a>) -> SequenceRecord<'a> {
+        SequenceRecord::new(fastq.id.into(), fastq.seq.into(), Some(fastq.qual.into()))
+    }
+}
+
+/// Reject a buffer that does not start a FASTQ record, tolerating the stray
+/// trailing newlines that sometimes pad the end of a file.
+#[inline]
+fn check_fastq_start(buf: &[u8], last: bool) -> Result<(), ParseError> {
+    if buf[0] != b'@' {
+        // sometimes there are extra returns at the end of a file so we shouldn't blow up
+        if !(last && (buf[0] == b'\r' || buf[0] == b'\n')) {
+            let context = String::from_utf8_lossy(&buf[..min(64, buf.len())]);
+            return Err(ParseError::new(
+                "FASTQ record must start with '@'",
+                ParseErrorType::InvalidHeader,
+            )
+            .context(context));
+        }
+    }
+    Ok(())
+}
+
 /// An iterator that parses a buffer into a sequence of FASTQRecords
 pub struct FastqParser<'a> {
     buf: &'a [u8],
     last: bool,
     pos: usize,
+    /// Set when the iterator last yielded `None` because the buffer was cut
+    /// off mid-record rather than because the input was exhausted. The buffer
+    /// layer reads this to decide whether to refill or terminate.
+    incomplete: bool,
 }
 
 impl<'a> FastqParser<'a> {
     pub fn new(buf: &'a [u8], last: bool) -> Result<Self, ParseError> {
-        if buf[0] != b'@' {
-            // sometimes there are extra returns at the end of a file so we shouldn't blow up
-            if !(last && (buf[0] == b'\r' && buf[0] == b'\n')) {
-                let context = String::from_utf8_lossy(&buf[..min(64, buf.len())]);
-                let e = ParseError::new(
-                    "FASTQ record must start with '@'",
-                    ParseErrorType::InvalidHeader,
-                )
-                .context(context);
-                return Err(e);
-            }
-        }
+        check_fastq_start(buf, last)?;
+        Ok(FastqParser {
+            buf,
+            last,
+            pos: 0,
+            incomplete: false,
+        })
+    }
 
-        Ok(FastqParser { buf, last, pos: 0 })
+    /// Mark the current record as incomplete and stop iterating for now.
+    #[inline]
+    fn need_more(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.incomplete = true;
+        None
     }
 }
 
@@ -60,6 +165,7 @@ impl<'a> Iterator for FastqParser<'a> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
+        self.incomplete = false;
         let buf = &self.buf[self.pos..];
         if buf.is_empty() {
             return None;
@@ -72,21 +178,21 @@ impl<'a> Iterator for FastqParser<'a> {
         let id_end;
         match memchr(b'\n', &buf) {
             Some(i) => id_end = i + 1,
-            None => return None,
+            None => return self.need_more(),
         };
         let mut id = &buf[1..id_end - 1];
 
         let seq_end;
         match memchr_both(b'\n', b'+', &buf[id_end..]) {
             Some(i) => seq_end = id_end + i + 1,
-            None => return None,
+            None => return self.need_more(),
         };
         let mut seq = &buf[id_end..seq_end - 1];
 
         let id2_end;
         match memchr(b'\n', &buf[seq_end..]) {
             Some(i) => id2_end = seq_end + i + 1,
-            None => return None,
+            None => return self.need_more(),
         };
         let id2 = &buf[seq_end..id2_end - 1];
 
@@ -97,12 +203,20 @@ impl<'a> Iterator for FastqParser<'a> {
         if qual_end > buf.len() {
             if !self.last {
                 // we need to pull more into the buffer
-                return None;
+                return self.need_more();
             }
             // now do some math to figure out if the file doesn't end with a newline
             let windows_ending = if seq.last() == Some(&b'\r') { 1 } else { 0 };
             if qual_end != buf.len() + 1 + windows_ending {
-                return None;
+                // this is the final record and its quality length does not match
+                // the sequence: the record is malformed or truncated, so surface
+                // it as an error rather than silently dropping it
+                let context = String::from_utf8_lossy(id);
+                return Some(Err(ParseError::new(
+                    "Sequence and quality lengths differed",
+                    ParseErrorType::InvalidRecord,
+                )
+                .context(context)));
             }
             buffer_used -= 1 + windows_ending;
             qual_end -= windows_ending;
@@ -146,15 +260,280 @@ impl<'a> Iterator for FastqParser<'a> {
         }
 
         self.pos += buffer_used;
-        Some(Ok(FastqRecord { id, seq, id2, qual }))
+        Some(Ok(FastqRecord {
+            id,
+            seq,
+            id2,
+            qual,
+        }))
+    }
+}
+
+/// Strip a single trailing `\n` and optional preceding `\r` from a line slice.
+#[inline]
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    let mut end = line.len();
+    if end > 0 && line[end - 1] == b'\n' {
+        end -= 1;
+        if end > 0 && line[end - 1] == b'\r' {
+            end -= 1;
+        }
+    }
+    &line[..end]
+}
+
+/// An iterator that parses FASTQ whose sequence and quality are hard-wrapped
+/// across several lines (as produced by some older tools).
+///
+/// Because a wrapped quality line can legitimately begin with `@` or `+`, those
+/// markers can no longer delimit records; boundaries are found by counting
+/// sequence bases and matching that count against the quality characters
+/// instead. Records are stitched into owned buffers, so this yields the owned
+/// [`MultilineFastqRecord`] rather than the zero-copy [`FastqRecord`].
+pub struct MultilineFastqParser<'a> {
+    buf: &'a [u8],
+    last: bool,
+    pos: usize,
+    /// See [`FastqParser::incomplete`] field docs; same meaning here.
+    incomplete: bool,
+}
+
+impl<'a> MultilineFastqParser<'a> {
+    pub fn new(buf: &'a [u8], last: bool) -> Result<Self, ParseError> {
+        check_fastq_start(buf, last)?;
+        Ok(MultilineFastqParser {
+            buf,
+            last,
+            pos: 0,
+            incomplete: false,
+        })
+    }
+
+    /// Whether the most recent `None` meant "cut off mid-record, refill and
+    /// retry" rather than "no more records". Mirrors [`FastqParser`]'s signal.
+    pub fn incomplete(&self) -> bool {
+        self.incomplete
+    }
+
+    /// Mark the current record as incomplete and stop iterating for now.
+    #[inline]
+    fn need_more(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.incomplete = true;
+        None
+    }
+}
+
+impl<'a> Iterator for MultilineFastqParser<'a> {
+    type Item = Result<MultilineFastqRecord<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.incomplete = false;
+        let buf = &self.buf[self.pos..];
+        if buf.is_empty() {
+            return None;
+        }
+        if buf[0] == b'\n' {
+            // sometimes the last "record" is just newlines
+            return None;
+        }
+
+        // the id line, identical to the single-line flavour
+        let id_end = match memchr(b'\n', buf) {
+            Some(i) => i + 1,
+            None => return self.need_more(),
+        };
+        let mut id = &buf[1..id_end - 1];
+
+        // accumulate sequence lines until we hit the `+` separator line
+        let mut seq = Vec::new();
+        let mut cursor = id_end;
+        let sep_start = loop {
+            if cursor >= buf.len() {
+                // the separator hasn't arrived yet
+                return self.need_more();
+            }
+            if buf[cursor] == b'+' {
+                break cursor;
+            }
+            let line_end = match memchr(b'\n', &buf[cursor..]) {
+                Some(i) => cursor + i + 1,
+                // the current sequence line is cut off
+                None => return self.need_more(),
+            };
+            seq.extend_from_slice(strip_line_ending(&buf[cursor..line_end]));
+            cursor = line_end;
+        };
+        let seq_len = seq.len();
+
+        // the separator (second id) line
+        let sep_end = match memchr(b'\n', &buf[sep_start..]) {
+            Some(i) => sep_start + i + 1,
+            None => return self.need_more(),
+        };
+        let id2 = strip_line_ending(&buf[sep_start..sep_end]);
+
+        // accumulate quality lines until we have exactly `seq_len` chars. A
+        // leading `+` here is a genuine quality character, not a separator.
+        let mut qual = Vec::with_capacity(seq_len);
+        cursor = sep_end;
+        while qual.len() < seq_len {
+            if cursor >= buf.len() {
+                if self.last {
+                    // no more bytes are coming: the final record is truncated,
+                    // so fall through to the length check and surface it as an
+                    // error rather than silently dropping it
+                    break;
+                }
+                // the quality section is incomplete
+                return self.need_more();
+            }
+            let line_end = match memchr(b'\n', &buf[cursor..]) {
+                Some(i) => cursor + i + 1,
+                None => {
+                    if self.last {
+                        // last line of the file without a trailing newline
+                        qual.extend_from_slice(strip_line_ending(&buf[cursor..]));
+                        cursor = buf.len();
+                        break;
+                    }
+                    return self.need_more();
+                }
+            };
+            qual.extend_from_slice(strip_line_ending(&buf[cursor..line_end]));
+            cursor = line_end;
+        }
+
+        if qual.len() != seq_len {
+            let context = String::from_utf8_lossy(id);
+            return Some(Err(ParseError::new(
+                "Sequence and quality lengths differed",
+                ParseErrorType::InvalidRecord,
+            )
+            .context(context)));
+        }
+
+        // clean up any extra '\r' from the id
+        if !id.is_empty() && id[id.len() - 1] == b'\r' {
+            id = &id[..id.len() - 1];
+        }
+
+        self.pos += cursor;
+        Some(Ok(MultilineFastqRecord {
+            id,
+            seq,
+            id2,
+            qual,
+        }))
+    }
+}
+
+/// Build a 256-entry lookup table marking each allowed byte as `true`.
+const fn alphabet_table(allowed: &[u8]) -> [bool; 256] {
+    let mut table = [false; 256];
+    let mut i = 0;
+    while i < allowed.len() {
+        table[allowed[i] as usize] = true;
+        i += 1;
+    }
+    table
+}
+
+/// Bytes permitted by [`FastqRecord::validate_dna`] (upper- and lower-case ACGT).
+const DNA_TABLE: [bool; 256] = alphabet_table(b"ACGTacgt");
+/// Bytes permitted by [`FastqRecord::validate_dnan`] (upper- and lower-case ACGTN).
+const DNAN_TABLE: [bool; 256] = alphabet_table(b"ACGTNacgtn");
+
+/// Return `true` if every byte of `seq` is present in `table`.
+///
+/// Indexing a precomputed `[bool; 256]` per byte vectorizes far better than
+/// chained comparisons, and swapping the table is all it takes to change the
+/// permitted alphabet (e.g. case-insensitivity or custom sets).
+#[inline]
+pub fn validate_alphabet(seq: &[u8], table: &[bool; 256]) -> bool {
+    seq.iter().all(|&b| table[b as usize])
+}
+
+/// Return the position of the first byte of `seq` not present in `table`, or
+/// `None` if every byte is allowed.
+#[inline]
+pub fn first_invalid(seq: &[u8], table: &[bool; 256]) -> Option<usize> {
+    seq.iter().position(|&b| !table[b as usize])
+}
+
+/// Serialize a record back out as FASTQ.
+///
+/// Emits the `@id` line, the sequence, the `+` separator line, and the quality
+/// line. The separator preserves the record's parsed second header (`id2`,
+/// whose leading `+` is written only once) so a record read with a populated
+/// `+id2...` line round-trips faithfully; when `id2` holds nothing beyond the
+/// `+`, `repeat_id` chooses between a bare `+` and repeating the id.
+/// This is the inverse of [`FastqParser`] and enables round-tripping records
+/// without leaving the crate.
+pub fn write_fastq<W: Write>(
+    record: &FastqRecord,
+    writer: &mut W,
+    repeat_id: bool,
+) -> io::Result<()> {
+    writer.write_all(b"@")?;
+    writer.write_all(record.id)?;
+    writer.write_all(b"\n")?;
+    writer.write_all(record.seq)?;
+    writer.write_all(b"\n+")?;
+    // the parser keeps the leading '+' in `id2`
+    let id2 = match record.id2.first() {
+        Some(b'+') => &record.id2[1..],
+        _ => record.id2,
+    };
+    if !id2.is_empty() {
+        writer.write_all(id2)?;
+    } else if repeat_id {
+        writer.write_all(record.id)?;
+    }
+    writer.write_all(b"\n")?;
+    writer.write_all(record.qual)?;
+    writer.write_all(b"\n")
+}
+
+/// Serialize an id/sequence pair as FASTA.
+///
+/// When `line_width` is `Some(n)` the sequence is hard-wrapped every `n`
+/// columns; `None` writes it on a single line. Dropping a FASTQ record's
+/// quality and passing its id and sequence here performs a FASTQ -> FASTA
+/// conversion entirely within needletail.
+pub fn write_fasta<W: Write>(
+    id: &[u8],
+    seq: &[u8],
+    writer: &mut W,
+    line_width: Option<usize>,
+) -> io::Result<()> {
+    writer.write_all(b">")?;
+    writer.write_all(id)?;
+    writer.write_all(b"\n")?;
+    match line_width {
+        Some(width) if width > 0 => {
+            for chunk in seq.chunks(width) {
+                writer.write_all(chunk)?;
+                writer.write_all(b"\n")?;
+            }
+        }
+        _ => {
+            writer.write_all(seq)?;
+            writer.write_all(b"\n")?;
+        }
     }
+    Ok(())
 }
 
 impl<'a> RecParser<'a> for FastqParser<'a> {
     type Header = ();
 
     fn from_buffer(buf: &[u8], last: bool) -> FastqParser {
-        FastqParser { buf, last, pos: 0 }
+        FastqParser {
+            buf,
+            last,
+            pos: 0,
+            incomplete: false,
+        }
     }
 
     fn header(&mut self) -> Result<Self::Header, ParseError> {
@@ -168,13 +547,26 @@ impl<'a> RecParser<'a> for FastqParser<'a> {
     fn used(&self) -> usize {
         self.pos
     }
+
+    /// Whether the most recent `None` from the iterator meant "the buffer is
+    /// cut off mid-record, refill and retry" rather than "no more records".
+    /// Mirrors entab's `.incomplete()` parse-error tag; `RecBuffer::refill`
+    /// branches on it to decide whether to pull more bytes or terminate. The
+    /// trait default is `false`, so parsers that never stop mid-record (e.g.
+    /// the FASTA parser) need not override it.
+    fn incomplete(&self) -> bool {
+        self.incomplete
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
 
-    use super::FastqParser;
+    use super::{
+        detect_quality_offset, first_invalid, validate_alphabet, write_fasta, write_fastq,
+        FastqParser, MultilineFastqParser, DNA_TABLE,
+    };
     use crate::formats::buffer::{RecBuffer, RecParser};
     use crate::formats::parse_sequence_reader;
     use crate::util::ParseErrorType;
@@ -400,26 +792,158 @@ mod test {
                 // record is incomplete
                 panic!("No initial record should be parsed")
             }
+            // the parser stopped because the record was cut off, not because
+            // the input was exhausted
+            assert!(rec_buffer.incomplete());
             rec_buffer.used()
         };
 
         // refill the buffer, but we're not done quite yet
         assert_eq!(rec_reader.refill(used).unwrap(), false);
 
-        // now we should see both records
+        // now we should see the first record
+        let used = {
+            let mut rec_buffer = FastqParser::from_buffer(&rec_reader.buf, rec_reader.last);
+
+            // there should be a record assuming the parser
+            // handled the buffer boundary
+            let iterated_seq = rec_buffer.by_ref().next();
+            let seq = iterated_seq.unwrap();
+            assert_eq!(seq.unwrap().id, b"A");
+
+            // but not another because the buffer's too short: the final record
+            // is cut off, which is an incomplete signal rather than end-of-input
+            let iterated_seq = rec_buffer.by_ref().next();
+            assert!(iterated_seq.is_none());
+            assert!(rec_buffer.incomplete());
+            rec_buffer.used()
+        };
+
+        // refill once more; now the whole input is buffered and flagged last
+        rec_reader.refill(used).unwrap();
         let mut rec_buffer = FastqParser::from_buffer(&rec_reader.buf, rec_reader.last);
+        let last = rec_buffer.by_ref().next().unwrap().unwrap();
+        assert_eq!(last.id, b"B");
+        assert_eq!(&last.seq[..], b"A");
+        assert_eq!(&last.qual[..], b"!");
+        // the trailing `None` here is genuine end-of-input, not a cut-off
+        // record, so the refill loop must see `incomplete() == false`
+        assert!(rec_buffer.by_ref().next().is_none());
+        assert!(!rec_buffer.incomplete());
+    }
+
+    #[test]
+    fn test_truncated_final_record() {
+        // the last record's quality is two bytes short of its sequence; with
+        // no more buffer coming this is a truncation, not "need more data",
+        // and must be reported rather than silently dropped
+        let mut fp = FastqParser::new(b"@test\nACGT\n+\nII", true).unwrap();
+        let result = fp.next().unwrap();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().error_type,
+            ParseErrorType::InvalidRecord
+        );
+        assert!(!fp.incomplete());
+    }
+
+    #[test]
+    fn test_quality_decoding() {
+        let input = b"@test\nACGT\n+\n!+5I\n";
+        let mut fp = FastqParser::new(input, true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+        let scores: Vec<u8> = rec.phred_scores(33).collect();
+        assert_eq!(scores, vec![0, 10, 20, 40]);
+        let probs: Vec<f64> = rec.error_probs(33).collect();
+        assert!((probs[0] - 1.0).abs() < 1e-12);
+        assert!((probs[1] - 0.1).abs() < 1e-12);
+
+        assert_eq!(detect_quality_offset(b"!+5I"), Some(33));
+        assert_eq!(detect_quality_offset(b"@ABChijk"), Some(64));
+        assert_eq!(detect_quality_offset(b""), None);
+    }
+
+    #[test]
+    fn test_validate_alphabet() {
+        let mut fp = FastqParser::new(b"@t\nACGTacgt\n+\n!!!!!!!!\n", true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+        assert!(rec.validate_dna());
+        assert!(rec.validate_dnan());
+
+        let mut fp = FastqParser::new(b"@t\nACGTN\n+\n!!!!!\n", true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+        assert!(!rec.validate_dna());
+        assert!(rec.validate_dnan());
+
+        // the table-swap helpers remain available for custom alphabets
+        assert!(validate_alphabet(b"ACGTacgt", &DNA_TABLE));
+        assert_eq!(first_invalid(b"ACGT", &DNA_TABLE), None);
+        assert_eq!(first_invalid(b"ACXT", &DNA_TABLE), Some(2));
+    }
+
+    #[test]
+    fn test_write_roundtrip() {
+        let input = b"@test\nACGTACGT\n+\n~~~~IIII\n";
+        let mut fp = FastqParser::new(input, true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+
+        let mut out = Vec::new();
+        write_fastq(&rec, &mut out, false).unwrap();
+        assert_eq!(&out[..], &input[..]);
+
+        // dropping quality and wrapping yields FASTA
+        let mut out = Vec::new();
+        write_fasta(rec.id, rec.seq, &mut out, Some(4)).unwrap();
+        assert_eq!(&out[..], b">test\nACGT\nACGT\n");
+
+        // a populated second header is written back verbatim on the '+' line
+        let input = b"@test\nACGT\n+test\nIIII\n";
+        let mut fp = FastqParser::new(input, true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+        let mut out = Vec::new();
+        write_fastq(&rec, &mut out, false).unwrap();
+        assert_eq!(&out[..], &input[..]);
+    }
 
-        // there should be a record assuming the parser
-        // handled the buffer boundary
-        let iterated_seq = rec_buffer.by_ref().next();
-        let seq = iterated_seq.unwrap();
-        assert_eq!(seq.unwrap().id, b"A");
+    #[test]
+    fn test_multiline_fastq() {
+        // sequence and quality wrapped across two lines each; note the quality
+        // section contains a line that begins with '+', which must not be
+        // mistaken for the separator
+        let test = b"@test\nACGT\nACGT\n+\n+III\nIIII\n";
+        let mut fp = MultilineFastqParser::new(test, true).unwrap();
+        let rec = fp.next().unwrap().unwrap();
+        assert_eq!(&rec.id[..], b"test");
+        assert_eq!(&rec.seq[..], b"ACGTACGT");
+        assert_eq!(&rec.qual[..], b"+IIIIIII");
+        assert!(fp.next().is_none());
+
+        // overshooting the sequence length is still a length mismatch
+        let test = b"@test\nACGT\n+\nIIIII\n";
+        let mut fp = MultilineFastqParser::new(test, true).unwrap();
+        let result = fp.next().unwrap();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().error_type,
+            ParseErrorType::InvalidRecord
+        );
 
-        // but not another because the buffer's too short
-        let iterated_seq = rec_buffer.by_ref().next();
-        assert!(iterated_seq.is_none());
+        // an incomplete quality section mid-buffer asks for more data
+        let test = b"@test\nACGT\nACGT\n+\nIIII\n";
+        let mut fp = MultilineFastqParser::new(test, false).unwrap();
+        assert!(fp.next().is_none());
 
-        // TODO: refill and check for the last record
+        // but the same short quality section at end of input is a truncated
+        // final record, which must be reported rather than silently dropped
+        let test = b"@test\nACGT\nACGT\n+\nIIII\n";
+        let mut fp = MultilineFastqParser::new(test, true).unwrap();
+        let result = fp.next().unwrap();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().error_type,
+            ParseErrorType::InvalidRecord
+        );
+        assert!(!fp.incomplete());
     }
 
     #[test]